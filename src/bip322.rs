@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use bitcoin::absolute::LockTime;
+use bitcoin::consensus::{deserialize, serialize};
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::key::{Keypair, TapTweak};
+use bitcoin::secp256k1::{self, Message, Secp256k1};
+use bitcoin::sighash::{Prevouts, SighashCache};
+use bitcoin::transaction::Version;
+use bitcoin::{
+    Amount, EcdsaSighashType, OutPoint, ScriptBuf, Sequence, TapSighashType, Transaction, TxIn,
+    TxOut, Txid, Witness,
+};
+
+use crate::error::Bip322Error;
+use crate::Address;
+
+const TAG: &str = "BIP0322-signed-message";
+
+fn message_hash(message: &[u8]) -> [u8; 32] {
+    let tag_hash = sha256::Hash::hash(TAG.as_bytes());
+    let mut engine = sha256::Hash::engine();
+    engine.input(&tag_hash[..]);
+    engine.input(&tag_hash[..]);
+    engine.input(message);
+    sha256::Hash::from_engine(engine).to_byte_array()
+}
+
+fn to_spend_tx(script_pubkey: &ScriptBuf, message: &[u8]) -> Transaction {
+    let message_hash = message_hash(message);
+    let script_sig = bitcoin::blockdata::script::Builder::new()
+        .push_opcode(bitcoin::opcodes::OP_0)
+        .push_slice(<&bitcoin::script::PushBytes>::try_from(message_hash.as_slice()).unwrap())
+        .into_script();
+
+    Transaction {
+        version: Version(0),
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint {
+                txid: Txid::from_byte_array([0u8; 32]),
+                vout: 0xFFFFFFFF,
+            },
+            script_sig,
+            sequence: Sequence(0),
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey: script_pubkey.clone(),
+        }],
+    }
+}
+
+fn to_sign_tx(to_spend_txid: Txid, witness: Witness) -> Transaction {
+    Transaction {
+        version: Version(0),
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint {
+                txid: to_spend_txid,
+                vout: 0,
+            },
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence(0),
+            witness,
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey: bitcoin::blockdata::script::Builder::new()
+                .push_opcode(bitcoin::opcodes::all::OP_RETURN)
+                .into_script(),
+        }],
+    }
+}
+
+/// Produces a BIP322 "simple" signature: the consensus-serialized witness stack that spends
+/// the virtual `to_spend` output committing to `message`, for a P2WPKH or P2TR key-path address.
+#[uniffi::export]
+pub fn bip322_sign_message(
+    address: Arc<Address>,
+    message: Vec<u8>,
+    private_key: Vec<u8>,
+) -> Result<Vec<u8>, Bip322Error> {
+    let script_pubkey = address.0.script_pubkey();
+    let to_spend = to_spend_tx(&script_pubkey, &message);
+    let to_spend_txid = to_spend.compute_txid();
+    let secp = Secp256k1::new();
+
+    let secret_key = secp256k1::SecretKey::from_slice(&private_key)
+        .map_err(|_| Bip322Error::MalformedSignature)?;
+
+    let witness = if script_pubkey.is_p2wpkh() {
+        let public_key = secp256k1::PublicKey::from_secret_key(&secp, &secret_key);
+        let to_sign = to_sign_tx(to_spend_txid, Witness::new());
+        let sighash = SighashCache::new(&to_sign)
+            .p2wpkh_signature_hash(
+                0,
+                &script_pubkey,
+                to_spend.output[0].value,
+                EcdsaSighashType::All,
+            )
+            .map_err(|_| Bip322Error::ExtractionError)?;
+        let message = Message::from_digest(sighash.to_byte_array());
+        let signature = secp.sign_ecdsa(&message, &secret_key);
+
+        let mut sig_bytes = signature.serialize_der().to_vec();
+        sig_bytes.push(EcdsaSighashType::All.to_u32() as u8);
+
+        let mut witness = Witness::new();
+        witness.push(sig_bytes);
+        witness.push(public_key.serialize());
+        witness
+    } else if script_pubkey.is_p2tr() {
+        let keypair = Keypair::from_secret_key(&secp, &secret_key);
+        let tweaked = keypair.tap_tweak(&secp, None);
+        let to_sign = to_sign_tx(to_spend_txid, Witness::new());
+        let prevouts = vec![to_spend.output[0].clone()];
+        let sighash = SighashCache::new(&to_sign)
+            .taproot_key_spend_signature_hash(
+                0,
+                &Prevouts::All(&prevouts),
+                TapSighashType::Default,
+            )
+            .map_err(|_| Bip322Error::ExtractionError)?;
+        let message = Message::from_digest(sighash.to_byte_array());
+        let signature = secp.sign_schnorr_no_aux_rand(&message, &tweaked.to_inner());
+
+        let mut witness = Witness::new();
+        witness.push(signature.as_ref().to_vec());
+        witness
+    } else {
+        return Err(Bip322Error::InvalidAddress);
+    };
+
+    Ok(serialize(&witness))
+}
+
+/// Verifies a BIP322 "simple" signature against `message` for a P2WPKH or P2TR key-path
+/// address, by reconstructing the `to_spend`/`to_sign` transactions and checking the witness.
+///
+/// P2WPKH is checked by running the real consensus script interpreter (via `bitcoinconsensus`)
+/// against `to_spend`'s scriptPubKey, which rejects any witness the interpreter itself would
+/// reject, including non-standard encodings. The interpreter's pre-taproot verify flags treat
+/// an unrecognized (v1+) witness program as anyone-can-spend, so it cannot be used to check a
+/// P2TR key-path signature; that case recomputes the BIP341 key-spend sighash directly and
+/// verifies the schnorr signature against the output key.
+#[uniffi::export]
+pub fn bip322_verify_message(
+    address: Arc<Address>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+) -> Result<bool, Bip322Error> {
+    let script_pubkey = address.0.script_pubkey();
+    let witness: Witness =
+        deserialize(&signature).map_err(|_| Bip322Error::MalformedSignature)?;
+
+    let to_spend = to_spend_tx(&script_pubkey, &message);
+    let to_spend_txid = to_spend.compute_txid();
+    let to_sign = to_sign_tx(to_spend_txid, witness.clone());
+
+    if script_pubkey.is_p2wpkh() {
+        let to_sign_bytes = serialize(&to_sign);
+        match script_pubkey.verify(0, to_spend.output[0].value, &to_sign_bytes) {
+            Ok(()) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    } else if script_pubkey.is_p2tr() {
+        let sig_bytes = witness
+            .iter()
+            .next()
+            .ok_or(Bip322Error::MalformedSignature)?;
+        let program = script_pubkey.as_bytes();
+        let xonly_bytes = &program[program.len() - 32..];
+        let output_key = secp256k1::XOnlyPublicKey::from_slice(xonly_bytes)
+            .map_err(|_| Bip322Error::MalformedSignature)?;
+
+        let sighash_type = if sig_bytes.len() == 65 {
+            TapSighashType::from_consensus_u8(sig_bytes[64])
+                .map_err(|_| Bip322Error::MalformedSignature)?
+        } else {
+            TapSighashType::Default
+        };
+        let sig_64 = &sig_bytes[..64];
+        let signature = secp256k1::schnorr::Signature::from_slice(sig_64)
+            .map_err(|_| Bip322Error::MalformedSignature)?;
+
+        let prevouts = vec![to_spend.output[0].clone()];
+        let sighash = SighashCache::new(&to_sign)
+            .taproot_key_spend_signature_hash(0, &Prevouts::All(&prevouts), sighash_type)
+            .map_err(|_| Bip322Error::ExtractionError)?;
+        let message = Message::from_digest(sighash.to_byte_array());
+
+        let secp = Secp256k1::new();
+        Ok(secp.verify_schnorr(&signature, &message, &output_key).is_ok())
+    } else {
+        Err(Bip322Error::InvalidAddress)
+    }
+}
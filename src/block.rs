@@ -0,0 +1,248 @@
+use std::sync::Arc;
+
+use bitcoin::consensus::deserialize;
+use bitcoin::hashes::{sha256d, Hash, HashEngine};
+use bitcoin::BlockHash;
+
+use crate::error::{BlockHeaderError, EncodeError};
+use crate::Txid;
+
+/// The 2016-block retarget window, in seconds.
+const TARGET_TIMESPAN: i64 = 14 * 24 * 60 * 60;
+/// Mainnet's `pow_limit` in compact form: the easiest possible target, i.e. difficulty 1.
+const MAX_TARGET_BITS: u32 = 0x1d00_ffff;
+
+/// An 80-byte Bitcoin block header, exposed for SPV-style validation without a full node.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Object)]
+pub struct BlockHeader(pub bitcoin::block::Header);
+
+#[uniffi::export]
+impl BlockHeader {
+    #[uniffi::constructor]
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, EncodeError> {
+        let header: bitcoin::block::Header = deserialize(bytes)?;
+        Ok(BlockHeader(header))
+    }
+
+    pub fn version(&self) -> i32 {
+        self.0.version.to_consensus()
+    }
+
+    pub fn prev_blockhash(&self) -> BlockHash {
+        self.0.prev_blockhash
+    }
+
+    pub fn merkle_root(&self) -> String {
+        self.0.merkle_root.to_string()
+    }
+
+    pub fn time(&self) -> u32 {
+        self.0.time
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.0.bits.to_consensus()
+    }
+
+    pub fn nonce(&self) -> u32 {
+        self.0.nonce
+    }
+
+    pub fn compute_block_hash(&self) -> BlockHash {
+        self.0.block_hash()
+    }
+
+    /// The 256-bit target decoded from `bits`, as big-endian bytes.
+    pub fn target(&self) -> Vec<u8> {
+        let (target, _, _) = decode_compact_target(self.0.bits.to_consensus());
+        target.to_vec()
+    }
+
+    /// The difficulty relative to the easiest possible target (mainnet's `pow_limit`).
+    pub fn difficulty(&self) -> u64 {
+        let bits = self.0.bits.to_consensus();
+        let mut shift = (bits >> 24) as i32;
+        let mantissa = (bits & 0x00ff_ffff) as f64;
+        let mut difficulty = 0x0000_ffffu32 as f64 / mantissa;
+        while shift < 29 {
+            difficulty *= 256.0;
+            shift += 1;
+        }
+        while shift > 29 {
+            difficulty /= 256.0;
+            shift -= 1;
+        }
+        difficulty as u64
+    }
+
+    /// Checks that the block's double-SHA256 hash, read as a little-endian 256-bit integer,
+    /// does not exceed the target decoded from `bits`. Returns the block hash on success.
+    pub fn validate_pow(&self) -> Result<BlockHash, BlockHeaderError> {
+        let (target, negative, overflow) = decode_compact_target(self.0.bits.to_consensus());
+        if negative || overflow {
+            return Err(BlockHeaderError::InvalidTarget);
+        }
+
+        let hash = self.0.block_hash();
+        let mut hash_be = hash.to_byte_array();
+        hash_be.reverse();
+
+        if hash_be <= target {
+            Ok(hash)
+        } else {
+            Err(BlockHeaderError::InsufficientWork)
+        }
+    }
+}
+
+impl_from_core_type!(BlockHeader, bitcoin::block::Header);
+impl_from_ffi_type!(BlockHeader, bitcoin::block::Header);
+
+/// Decodes a compact `nbits` target into big-endian 256-bit bytes, plus the negative and
+/// overflow flags, following the same rules as Bitcoin Core's `arith_uint256::SetCompact`.
+pub(crate) fn decode_compact_target(compact: u32) -> ([u8; 32], bool, bool) {
+    let size = (compact >> 24) as i64;
+    let mut word = compact & 0x007f_ffff;
+    let negative = word != 0 && (compact & 0x0080_0000) != 0;
+    let overflow =
+        word != 0 && (size > 34 || (word > 0xff && size > 33) || (word > 0xffff && size > 32));
+
+    let target = if size <= 3 {
+        word >>= 8 * (3 - size) as u32;
+        let mut out = [0u8; 32];
+        out[28..32].copy_from_slice(&word.to_be_bytes());
+        out
+    } else {
+        shift_left_256(word, 8 * (size - 3) as u32)
+    };
+
+    (target, negative, overflow)
+}
+
+/// Left-shifts `value` (treated as the low bits of a 256-bit unsigned integer) by `shift_bits`,
+/// dropping any bits that overflow past bit 255, and returns the result as big-endian bytes.
+fn shift_left_256(value: u32, shift_bits: u32) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    for bit in 0..24u32 {
+        if (value >> bit) & 1 == 1 {
+            let pos = bit + shift_bits;
+            if pos < 256 {
+                let byte_index = 31 - (pos / 8) as usize;
+                out[byte_index] |= 1 << (pos % 8);
+            }
+        }
+    }
+    out
+}
+
+/// Verifies a Merkle inclusion proof for `txid` against `merkle_root`, walking up from the leaf
+/// using `siblings`: bit `k` of `index` selects whether the running hash is the left or right
+/// input to the next double-SHA256 combination.
+#[uniffi::export]
+pub fn verify_merkle_proof(
+    txid: Txid,
+    merkle_root: BlockHash,
+    index: u32,
+    siblings: Vec<Vec<u8>>,
+) -> bool {
+    let mut running = txid.to_byte_array();
+
+    for (level, sibling) in siblings.iter().enumerate() {
+        let mut engine = sha256d::Hash::engine();
+        if (index >> level) & 1 == 0 {
+            engine.input(&running);
+            engine.input(sibling);
+        } else {
+            engine.input(sibling);
+            engine.input(&running);
+        }
+        running = sha256d::Hash::from_engine(engine).to_byte_array();
+    }
+
+    running == merkle_root.to_byte_array()
+}
+
+/// Applies the 2016-block difficulty retarget rule and returns the new compact `nbits`.
+#[uniffi::export]
+pub fn compute_new_target(first_time: u32, last_time: u32, last_bits: u32) -> u32 {
+    let min_timespan = TARGET_TIMESPAN / 4;
+    let max_timespan = TARGET_TIMESPAN * 4;
+    let actual_timespan =
+        (last_time as i64 - first_time as i64).clamp(min_timespan, max_timespan) as u64;
+
+    let (old_target, _, _) = decode_compact_target(last_bits);
+    let scaled = mul_u64(&old_target, actual_timespan);
+    let mut new_target = div_u64(&scaled, TARGET_TIMESPAN as u64);
+
+    let (max_target, _, _) = decode_compact_target(MAX_TARGET_BITS);
+    if new_target > max_target {
+        new_target = max_target;
+    }
+
+    encode_compact_target(&new_target)
+}
+
+/// Checks that each header's `prev_blockhash` links to the previous header and that each
+/// header's proof-of-work meets its own stated target.
+#[uniffi::export]
+pub fn verify_target_chain(headers: Vec<Arc<BlockHeader>>) -> bool {
+    for pair in headers.windows(2) {
+        if pair[1].0.prev_blockhash != pair[0].0.block_hash() {
+            return false;
+        }
+    }
+    headers.iter().all(|header| header.validate_pow().is_ok())
+}
+
+/// Multiplies a big-endian 256-bit unsigned integer by a `u64` scalar, dropping any bits that
+/// overflow past bit 255.
+fn mul_u64(value: &[u8; 32], factor: u64) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    let mut carry: u128 = 0;
+    for i in (0..32).rev() {
+        let product = value[i] as u128 * factor as u128 + carry;
+        out[i] = (product & 0xff) as u8;
+        carry = product >> 8;
+    }
+    out
+}
+
+/// Divides a big-endian 256-bit unsigned integer by a `u64` scalar.
+fn div_u64(value: &[u8; 32], divisor: u64) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    let mut remainder: u128 = 0;
+    for i in 0..32 {
+        let chunk = (remainder << 8) | value[i] as u128;
+        out[i] = (chunk / divisor as u128) as u8;
+        remainder = chunk % divisor as u128;
+    }
+    out
+}
+
+/// Encodes a big-endian 256-bit unsigned integer as a compact `nbits` value, following the
+/// same rules as Bitcoin Core's `arith_uint256::GetCompact`.
+fn encode_compact_target(target: &[u8; 32]) -> u32 {
+    let size = match target.iter().position(|&byte| byte != 0) {
+        Some(index) => 32 - index,
+        None => 0,
+    };
+
+    let mut mantissa: u32 = if size <= 3 {
+        let mut word = 0u32;
+        for &byte in &target[32 - size..] {
+            word = (word << 8) | byte as u32;
+        }
+        word << (8 * (3 - size))
+    } else {
+        let start = 32 - size;
+        ((target[start] as u32) << 16) | ((target[start + 1] as u32) << 8) | target[start + 2] as u32
+    };
+
+    let mut size = size as u32;
+    if mantissa & 0x0080_0000 != 0 {
+        mantissa >>= 8;
+        size += 1;
+    }
+
+    (size << 24) | mantissa
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use bitcoin::consensus::{deserialize, serialize};
+
+use crate::error::EncodeError;
+use crate::{BlockHeader, Script, Transaction, TxOut};
+
+/// Free-standing consensus (de)serialization entry points for binding users who receive raw
+/// wire bytes directly (e.g. from a P2P connection) rather than through one of the typed
+/// constructors.
+#[uniffi::export]
+pub fn serialize_transaction(tx: Arc<Transaction>) -> Vec<u8> {
+    serialize(&tx.0)
+}
+
+#[uniffi::export]
+pub fn deserialize_transaction(bytes: Vec<u8>) -> Result<Arc<Transaction>, EncodeError> {
+    let tx: bitcoin::Transaction = deserialize(&bytes)?;
+    Ok(Arc::new(tx.into()))
+}
+
+#[uniffi::export]
+pub fn serialize_tx_out(tx_out: TxOut) -> Vec<u8> {
+    serialize(&bitcoin::TxOut::from(tx_out))
+}
+
+#[uniffi::export]
+pub fn deserialize_tx_out(bytes: Vec<u8>) -> Result<TxOut, EncodeError> {
+    let tx_out: bitcoin::TxOut = deserialize(&bytes)?;
+    Ok(tx_out.into())
+}
+
+#[uniffi::export]
+pub fn serialize_script(script: Arc<Script>) -> Vec<u8> {
+    serialize(&script.0)
+}
+
+#[uniffi::export]
+pub fn deserialize_script(bytes: Vec<u8>) -> Result<Arc<Script>, EncodeError> {
+    let script: bitcoin::ScriptBuf = deserialize(&bytes)?;
+    Ok(Arc::new(script.into()))
+}
+
+#[uniffi::export]
+pub fn serialize_block_header(header: Arc<BlockHeader>) -> Vec<u8> {
+    serialize(&header.0)
+}
+
+#[uniffi::export]
+pub fn deserialize_block_header(bytes: Vec<u8>) -> Result<Arc<BlockHeader>, EncodeError> {
+    let header: bitcoin::block::Header = deserialize(&bytes)?;
+    Ok(Arc::new(header.into()))
+}
@@ -0,0 +1,94 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use miniscript::descriptor::DescriptorPublicKey;
+
+use crate::error::DescriptorError;
+use crate::{Address, Network, Script};
+
+/// An output descriptor (`miniscript::Descriptor<DescriptorPublicKey>`): a typed path from
+/// descriptor text to the spendable scripts/addresses it describes.
+#[derive(Debug, Clone, uniffi::Object)]
+pub struct Descriptor(miniscript::Descriptor<DescriptorPublicKey>);
+
+#[uniffi::export]
+impl Descriptor {
+    #[uniffi::constructor]
+    pub fn parse(descriptor: String) -> Result<Self, DescriptorError> {
+        let (desc_str, checksum) = match descriptor.split_once('#') {
+            Some((desc_str, checksum)) => (desc_str, Some(checksum)),
+            None => (descriptor.as_str(), None),
+        };
+
+        if let Some(checksum) = checksum {
+            let expected = miniscript::descriptor::checksum::desc_checksum(desc_str)
+                .map_err(|e| DescriptorError::Miniscript {
+                    error_message: e.to_string(),
+                })?;
+            if checksum != expected {
+                return Err(DescriptorError::InvalidChecksum);
+            }
+        }
+
+        let descriptor = miniscript::Descriptor::<DescriptorPublicKey>::from_str(desc_str)?;
+        Ok(Descriptor(descriptor))
+    }
+
+    /// Whether the descriptor contains a wildcard (`*`) and therefore describes a range of
+    /// addresses/scripts rather than a single one.
+    pub fn is_ranged(&self) -> bool {
+        self.0.has_wildcard()
+    }
+
+    /// The descriptor's checksum, as already produced and validated by `Display`/`from_str`
+    /// (`Display` always appends `#<checksum>`, so it's returned here rather than recomputed
+    /// over the already-checksummed string).
+    pub fn checksum(&self) -> Result<String, DescriptorError> {
+        self.0
+            .to_string()
+            .split_once('#')
+            .map(|(_, checksum)| checksum.to_string())
+            .ok_or(DescriptorError::InvalidChecksum)
+    }
+
+    /// Derives the concrete address at `index` for `network`. Errors if the descriptor is not
+    /// ranged and `index` is not 0, or if deriving requires a hardened child from an xpub.
+    pub fn address_at(
+        &self,
+        index: u32,
+        network: Network,
+    ) -> Result<Arc<Address>, DescriptorError> {
+        let derived = self.derive(index)?;
+        let address = derived
+            .address(network.into())
+            .map_err(|e| DescriptorError::Miniscript {
+                error_message: e.to_string(),
+            })?;
+        Ok(Arc::new(address.into()))
+    }
+
+    /// Derives the concrete output script at `index`. Errors if the descriptor is not ranged
+    /// and `index` is not 0, or if deriving requires a hardened child from an xpub.
+    pub fn script_at(&self, index: u32) -> Result<Arc<Script>, DescriptorError> {
+        let derived = self.derive(index)?;
+        Ok(Arc::new(derived.script_pubkey().into()))
+    }
+}
+
+impl Descriptor {
+    fn derive(
+        &self,
+        index: u32,
+    ) -> Result<miniscript::Descriptor<miniscript::descriptor::DefiniteDescriptorKey>, DescriptorError>
+    {
+        if !self.0.has_wildcard() && index != 0 {
+            return Err(DescriptorError::Miniscript {
+                error_message: "descriptor is not ranged; only index 0 is valid".to_string(),
+            });
+        }
+        Ok(self.0.at_derivation_index(index)?)
+    }
+}
+
+impl_from_core_type!(Descriptor, miniscript::Descriptor<DescriptorPublicKey>);
+impl_from_ffi_type!(Descriptor, miniscript::Descriptor<DescriptorPublicKey>);
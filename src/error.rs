@@ -96,6 +96,9 @@ pub enum ParseAmountError {
     #[error("amount out of range")]
     OutOfRange,
 
+    #[error("amount is negative")]
+    Negative,
+
     #[error("amount has a too high precision")]
     TooPrecise,
 
@@ -108,6 +111,12 @@ pub enum ParseAmountError {
     #[error("invalid character: {error_message}")]
     InvalidCharacter { error_message: String },
 
+    #[error("invalid amount format")]
+    InvalidFormat,
+
+    #[error("denomination is ambiguous or easily confused with another, e.g. a plural \"sats\"")]
+    PossiblyConfusingDenomination,
+
     // Has to handle non-exhaustive
     #[error("unknown parse amount error")]
     OtherParseAmountErr,
@@ -116,13 +125,22 @@ pub enum ParseAmountError {
 impl From<BitcoinParseAmountError> for ParseAmountError {
     fn from(error: BitcoinParseAmountError) -> Self {
         match error {
-            BitcoinParseAmountError::OutOfRange(_) => ParseAmountError::OutOfRange,
+            BitcoinParseAmountError::OutOfRange(e) => {
+                if e.is_negative() {
+                    ParseAmountError::Negative
+                } else {
+                    ParseAmountError::OutOfRange
+                }
+            }
             BitcoinParseAmountError::TooPrecise(_) => ParseAmountError::TooPrecise,
             BitcoinParseAmountError::MissingDigits(_) => ParseAmountError::MissingDigits,
             BitcoinParseAmountError::InputTooLarge(_) => ParseAmountError::InputTooLarge,
             BitcoinParseAmountError::InvalidCharacter(c) => ParseAmountError::InvalidCharacter {
                 error_message: c.to_string(),
             },
+            BitcoinParseAmountError::PossiblyConfusingDenomination(_) => {
+                ParseAmountError::PossiblyConfusingDenomination
+            }
             _ => ParseAmountError::OtherParseAmountErr,
         }
     }
@@ -132,14 +150,14 @@ impl From<BitcoinParseAmountError> for ParseAmountError {
 pub enum EncodeError {
     #[error("io error")]
     Io,
-    #[error("allocation of oversized vector")]
-    OversizedVectorAllocation,
+    #[error("allocation of oversized vector: requested {requested}, max {max}")]
+    OversizedVectorAllocation { requested: u64, max: u64 },
     #[error("invalid checksum: expected={expected} actual={actual}")]
     InvalidChecksum { expected: String, actual: String },
     #[error("non-minimal var int")]
     NonMinimalVarInt,
-    #[error("parse failed")]
-    ParseFailed,
+    #[error("parse failed: {error_message}")]
+    ParseFailed { error_message: String },
     #[error("unsupported segwit version: {flag}")]
     UnsupportedSegwitFlag { flag: u8 },
     // This is required because the bdk::bitcoin::consensus::encode::Error is non-exhaustive
@@ -151,8 +169,11 @@ impl From<BitcoinEncodeError> for EncodeError {
     fn from(error: BitcoinEncodeError) -> Self {
         match error {
             BitcoinEncodeError::Io(_) => EncodeError::Io,
-            BitcoinEncodeError::OversizedVectorAllocation { .. } => {
-                EncodeError::OversizedVectorAllocation
+            BitcoinEncodeError::OversizedVectorAllocation { requested, max } => {
+                EncodeError::OversizedVectorAllocation {
+                    requested: requested as u64,
+                    max: max as u64,
+                }
             }
             BitcoinEncodeError::InvalidChecksum { expected, actual } => {
                 EncodeError::InvalidChecksum {
@@ -161,7 +182,9 @@ impl From<BitcoinEncodeError> for EncodeError {
                 }
             }
             BitcoinEncodeError::NonMinimalVarInt => EncodeError::NonMinimalVarInt,
-            BitcoinEncodeError::ParseFailed(_) => EncodeError::ParseFailed,
+            BitcoinEncodeError::ParseFailed(msg) => EncodeError::ParseFailed {
+                error_message: msg.to_string(),
+            },
             BitcoinEncodeError::UnsupportedSegwitFlag(flag) => {
                 EncodeError::UnsupportedSegwitFlag { flag }
             }
@@ -336,6 +359,164 @@ impl From<BitcoinPsbtParseError> for PsbtParseError {
     }
 }
 
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum BlockHeaderError {
+    #[error("proof-of-work target overflows or has its sign bit set")]
+    InvalidTarget,
+    #[error("block hash does not meet the target required by its own bits field")]
+    InsufficientWork,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum SighashError {
+    #[error("input index {index} out of bounds for a transaction with {inputs_size} input(s)")]
+    IndexOutOfBounds { index: u32, inputs_size: u32 },
+    #[error("the number of prevouts does not match the number of transaction inputs")]
+    PrevoutsSize,
+    #[error("the prevouts kind does not match the sighash type")]
+    PrevoutsKind,
+    #[error("the prevout index is out of bounds")]
+    PrevoutIndex,
+    #[error("single sighash requested but the corresponding output is missing")]
+    SingleMissingOutput,
+    #[error("invalid sighash type")]
+    InvalidSighashType,
+    #[error("script pubkey is not a valid P2WPKH script")]
+    NotWpkh,
+    #[error("leaf hash must be exactly 32 bytes")]
+    InvalidLeafHash,
+    #[error("other sighash computation error")]
+    OtherSighashErr,
+}
+
+impl From<bitcoin::transaction::InputsIndexError> for SighashError {
+    fn from(error: bitcoin::transaction::InputsIndexError) -> Self {
+        SighashError::IndexOutOfBounds {
+            index: error.index as u32,
+            inputs_size: error.inputs_size as u32,
+        }
+    }
+}
+
+impl From<bitcoin::sighash::P2wpkhError> for SighashError {
+    fn from(error: bitcoin::sighash::P2wpkhError) -> Self {
+        match error {
+            bitcoin::sighash::P2wpkhError::NotWpkh => SighashError::NotWpkh,
+            bitcoin::sighash::P2wpkhError::Index(e) => e.into(),
+        }
+    }
+}
+
+impl From<bitcoin::sighash::TaprootError> for SighashError {
+    fn from(error: bitcoin::sighash::TaprootError) -> Self {
+        match error {
+            bitcoin::sighash::TaprootError::PrevoutsSize(_) => SighashError::PrevoutsSize,
+            bitcoin::sighash::TaprootError::PrevoutsKind(_) => SighashError::PrevoutsKind,
+            bitcoin::sighash::TaprootError::PrevoutIndex(_) => SighashError::PrevoutIndex,
+            bitcoin::sighash::TaprootError::SingleMissingOutput(_) => {
+                SighashError::SingleMissingOutput
+            }
+            bitcoin::sighash::TaprootError::InvalidSighashType(_) => {
+                SighashError::InvalidSighashType
+            }
+            _ => SighashError::OtherSighashErr,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum PaymentUriError {
+    #[error("uri does not start with the \"bitcoin:\" scheme")]
+    InvalidScheme,
+    #[error("uri is missing an address")]
+    MissingAddress,
+    #[error("invalid address: {error_message}")]
+    InvalidAddress { error_message: String },
+    #[error("invalid amount: {error_message}")]
+    InvalidAmount { error_message: String },
+    #[error("invalid query parameter encoding")]
+    InvalidParameter,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum Bip322Error {
+    #[error("address script type is not supported by this BIP322 implementation")]
+    InvalidAddress,
+    #[error("signature does not verify against the message and address")]
+    InvalidSignature,
+    #[error("failed to build or extract the BIP322 to_spend/to_sign transactions")]
+    ExtractionError,
+    #[error("witness stack, signature or private key is malformed")]
+    MalformedSignature,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum PsbtFinalizeError {
+    #[error("could not finalize input {index}: {reason}")]
+    InputError { index: u32, reason: String },
+    #[error("no satisfying witness/scriptSig could be constructed for an input")]
+    CouldNotSatisfy,
+    #[error("an absolute locktime spending condition has not yet been met")]
+    AbsoluteLocktimeNotMet,
+    #[error("a relative locktime spending condition has not yet been met")]
+    RelativeLocktimeNotMet,
+    #[error("input index is out of range of the PSBT's inputs")]
+    InputIndexOutOfRange,
+    // Returned when finalization fails without reporting any per-input error; every input error
+    // kind not matched above is reported as `InputError`.
+    #[error("other PSBT finalize error")]
+    OtherPsbtFinalizeErr,
+}
+
+impl From<miniscript::psbt::Error> for PsbtFinalizeError {
+    fn from(error: miniscript::psbt::Error) -> Self {
+        use miniscript::interpreter::Error as InterpreterError;
+        use miniscript::psbt::InputError;
+
+        let index = error.index as u32;
+        match error.error {
+            InputError::Interpreter(InterpreterError::AbsoluteLocktimeNotMet(_)) => {
+                PsbtFinalizeError::AbsoluteLocktimeNotMet
+            }
+            InputError::Interpreter(InterpreterError::RelativeLocktimeNotMet(_)) => {
+                PsbtFinalizeError::RelativeLocktimeNotMet
+            }
+            InputError::CouldNotSatisfyTr => PsbtFinalizeError::CouldNotSatisfy,
+            other => PsbtFinalizeError::InputError {
+                index,
+                reason: other.to_string(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum DescriptorError {
+    #[error("miniscript error: {error_message}")]
+    Miniscript { error_message: String },
+    #[error("descriptor checksum is invalid")]
+    InvalidChecksum,
+    #[error("derivation requires a hardened child of an xpub, which needs the private key")]
+    HardenedDerivationXpub,
+    // This is required because miniscript::Error is non-exhaustive.
+    #[error("other descriptor error")]
+    OtherDescriptorErr,
+}
+
+impl From<miniscript::Error> for DescriptorError {
+    fn from(error: miniscript::Error) -> Self {
+        DescriptorError::Miniscript {
+            error_message: error.to_string(),
+        }
+    }
+}
+
+impl From<miniscript::descriptor::ConversionError> for DescriptorError {
+    fn from(_: miniscript::descriptor::ConversionError) -> Self {
+        DescriptorError::HardenedDerivationXpub
+    }
+}
+
 #[derive(Debug, thiserror::Error, uniffi::Error)]
 pub enum ExtractTxError {
     #[error("feerate is too high {fee_rate}")]
@@ -1,5 +1,6 @@
 use bitcoin::address::{NetworkChecked, NetworkUnchecked};
 use bitcoin::consensus::{deserialize, serialize};
+use bitcoin::hex::DisplayHex;
 
 pub use bitcoin::BlockHash;
 pub use bitcoin::Txid;
@@ -10,6 +11,7 @@ use error::FeeRateError;
 use error::FromScriptError;
 use error::ParseAmountError;
 use error::PsbtError;
+use error::PsbtFinalizeError;
 use error::{AddressParseError, PsbtParseError};
 
 use std::fmt::Display;
@@ -18,7 +20,19 @@ use std::sync::Arc;
 
 #[macro_use]
 mod macros;
+pub mod bip322;
+pub mod block;
+pub mod consensus;
+pub mod descriptor;
 pub mod error;
+pub mod payment_uri;
+pub mod sighash;
+
+pub use bip322::{bip322_sign_message, bip322_verify_message};
+pub use block::BlockHeader;
+pub use descriptor::Descriptor;
+pub use payment_uri::PaymentUri;
+pub use sighash::{SighashCache, SighashType};
 
 #[derive(Debug, Clone, PartialEq, Eq, uniffi::Object)]
 #[uniffi::export(Display)]
@@ -51,6 +65,12 @@ impl Address {
         self.0.to_qr_uri()
     }
 
+    /// The Electrum/Esplora "scripthash" for this address's output script: `sha256(script)`
+    /// with the digest byte-reversed and hex-encoded, as used to key address subscriptions.
+    pub fn to_electrum_scripthash(&self) -> String {
+        electrum_scripthash(&self.0.script_pubkey())
+    }
+
     pub fn is_valid_for_network(&self, network: Network) -> bool {
         let address_str = self.0.to_string();
         if let Ok(unchecked_address) = address_str.parse::<bitcoin::Address<NetworkUnchecked>>() {
@@ -140,6 +160,20 @@ impl Script {
     pub fn to_bytes(&self) -> Vec<u8> {
         self.0.to_bytes()
     }
+
+    /// The Electrum/Esplora "scripthash": `sha256(script)` with the digest byte-reversed and
+    /// hex-encoded, as used to key address subscriptions by indexers like electrs.
+    pub fn to_electrum_scripthash(&self) -> String {
+        electrum_scripthash(&self.0)
+    }
+}
+
+fn electrum_scripthash(script: &bitcoin::Script) -> String {
+    use bitcoin::hashes::{sha256, Hash};
+
+    let mut digest = sha256::Hash::hash(script.as_bytes()).to_byte_array();
+    digest.reverse();
+    digest.to_lower_hex_string()
 }
 
 impl_from_core_type!(Script, bitcoin::ScriptBuf);
@@ -185,6 +219,12 @@ impl Amount {
         Ok(Amount(bitcoin_amount))
     }
 
+    #[uniffi::constructor]
+    pub fn from_str_in(value: String, denomination: Denomination) -> Result<Self, ParseAmountError> {
+        let amount = bitcoin::Amount::from_str_in(&value, denomination.into())?;
+        Ok(Amount(amount))
+    }
+
     pub fn to_sat(&self) -> u64 {
         self.0.to_sat()
     }
@@ -192,11 +232,73 @@ impl Amount {
     pub fn to_btc(&self) -> f64 {
         self.0.to_btc()
     }
+
+    pub fn to_string_in(&self, denomination: Denomination) -> String {
+        self.0.to_string_in(denomination.into())
+    }
 }
 
 impl_from_core_type!(Amount, bitcoin::Amount);
 impl_from_ffi_type!(Amount, bitcoin::Amount);
 
+/// A signed amount, in satoshis, able to represent negative values (e.g. a net balance change).
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Object)]
+pub struct SignedAmount(pub bitcoin::SignedAmount);
+
+#[uniffi::export]
+impl SignedAmount {
+    #[uniffi::constructor]
+    pub fn from_sat(sat: i64) -> Self {
+        SignedAmount(bitcoin::SignedAmount::from_sat(sat))
+    }
+
+    #[uniffi::constructor]
+    pub fn from_str_in(
+        value: String,
+        denomination: Denomination,
+    ) -> Result<Self, ParseAmountError> {
+        let amount = bitcoin::SignedAmount::from_str_in(&value, denomination.into())?;
+        Ok(SignedAmount(amount))
+    }
+
+    pub fn to_sat(&self) -> i64 {
+        self.0.to_sat()
+    }
+
+    pub fn to_string_in(&self, denomination: Denomination) -> String {
+        self.0.to_string_in(denomination.into())
+    }
+}
+
+impl_from_core_type!(SignedAmount, bitcoin::SignedAmount);
+impl_from_ffi_type!(SignedAmount, bitcoin::SignedAmount);
+
+/// A denomination a Bitcoin amount can be parsed from or formatted in.
+#[derive(Clone, Copy, Debug, uniffi::Enum)]
+pub enum Denomination {
+    Bitcoin,
+    CentiBitcoin,
+    MilliBitcoin,
+    MicroBitcoin,
+    Bit,
+    Satoshi,
+    MilliSatoshi,
+}
+
+impl From<Denomination> for bitcoin::Denomination {
+    fn from(denomination: Denomination) -> Self {
+        match denomination {
+            Denomination::Bitcoin => bitcoin::Denomination::Bitcoin,
+            Denomination::CentiBitcoin => bitcoin::Denomination::CentiBitcoin,
+            Denomination::MilliBitcoin => bitcoin::Denomination::MilliBitcoin,
+            Denomination::MicroBitcoin => bitcoin::Denomination::MicroBitcoin,
+            Denomination::Bit => bitcoin::Denomination::Bit,
+            Denomination::Satoshi => bitcoin::Denomination::Satoshi,
+            Denomination::MilliSatoshi => bitcoin::Denomination::MilliSatoshi,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
 pub struct TxIn {
     pub previous_output: OutPoint,
@@ -349,6 +451,123 @@ impl Psbt {
     pub fn fee(&self) -> Result<Arc<Amount>, PsbtError> {
         Ok(Arc::new(self.0.clone().fee()?.into()))
     }
+
+    pub fn inputs_len(&self) -> u64 {
+        self.0.inputs.len() as u64
+    }
+
+    pub fn outputs_len(&self) -> u64 {
+        self.0.outputs.len() as u64
+    }
+
+    pub fn witness_utxo(&self, input_index: u32) -> Option<TxOut> {
+        self.input(input_index)?.witness_utxo.clone().map(Into::into)
+    }
+
+    pub fn non_witness_utxo(&self, input_index: u32) -> Option<Arc<Transaction>> {
+        self.input(input_index)?
+            .non_witness_utxo
+            .clone()
+            .map(|tx| Arc::new((*tx).clone().into()))
+    }
+
+    pub fn sighash_type(&self, input_index: u32) -> Option<u32> {
+        self.input(input_index)?.sighash_type.map(|t| t.to_u32())
+    }
+
+    pub fn partial_sigs(&self, input_index: u32) -> Vec<PartialSig> {
+        match self.input(input_index) {
+            Some(input) => input
+                .partial_sigs
+                .iter()
+                .map(|(pubkey, sig)| PartialSig {
+                    pubkey: pubkey.to_bytes(),
+                    signature: sig.serialize().to_vec(),
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn tap_key_sig(&self, input_index: u32) -> Option<Vec<u8>> {
+        self.input(input_index)?.tap_key_sig.map(|sig| sig.to_vec())
+    }
+
+    pub fn redeem_script(&self, input_index: u32) -> Option<Arc<Script>> {
+        self.input(input_index)?
+            .redeem_script
+            .clone()
+            .map(|script| Arc::new(script.into()))
+    }
+
+    pub fn witness_script(&self, input_index: u32) -> Option<Arc<Script>> {
+        self.input(input_index)?
+            .witness_script
+            .clone()
+            .map(|script| Arc::new(script.into()))
+    }
+
+    pub fn add_partial_sig(
+        &self,
+        input_index: u32,
+        pubkey: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<Arc<Psbt>, PsbtError> {
+        let pubkey = bitcoin::PublicKey::from_slice(&pubkey)
+            .map_err(|e| PsbtError::InvalidPublicKey {
+                error_message: e.to_string(),
+            })?;
+        let signature =
+            bitcoin::ecdsa::Signature::from_slice(&signature).map_err(|e| {
+                PsbtError::InvalidEcdsaSignature {
+                    error_message: e.to_string(),
+                }
+            })?;
+
+        let mut psbt = self.0.clone();
+        let input = psbt
+            .inputs
+            .get_mut(input_index as usize)
+            .ok_or(PsbtError::PsbtUtxoOutOfBounds)?;
+        input.partial_sigs.insert(pubkey, signature);
+        Ok(Arc::new(Psbt(psbt)))
+    }
+
+    pub fn set_witness_utxo(&self, input_index: u32, txout: TxOut) -> Result<Arc<Psbt>, PsbtError> {
+        let mut psbt = self.0.clone();
+        let input = psbt
+            .inputs
+            .get_mut(input_index as usize)
+            .ok_or(PsbtError::PsbtUtxoOutOfBounds)?;
+        input.witness_utxo = Some(txout.into());
+        Ok(Arc::new(Psbt(psbt)))
+    }
+
+    /// Runs the Miniscript satisfaction/finalizer pass over every input: infers each input's
+    /// Miniscript from its witness/redeem script or descriptor, satisfies it using the
+    /// collected `partial_sigs`, preimages, and the transaction's timelocks, and sets
+    /// `final_script_sig`/`final_script_witness`, stripping the now-redundant fields.
+    pub fn finalize(&self) -> Result<Arc<Psbt>, PsbtFinalizeError> {
+        let secp = bitcoin::secp256k1::Secp256k1::verification_only();
+        let mut psbt = self.0.clone();
+        miniscript::psbt::PsbtExt::finalize_mut(&mut psbt, &secp)
+            .map_err(|errors| errors.into_iter().next().map(PsbtFinalizeError::from).unwrap_or(
+                PsbtFinalizeError::OtherPsbtFinalizeErr,
+            ))?;
+        Ok(Arc::new(Psbt(psbt)))
+    }
+}
+
+impl Psbt {
+    fn input(&self, input_index: u32) -> Option<&bitcoin::psbt::Input> {
+        self.0.inputs.get(input_index as usize)
+    }
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct PartialSig {
+    pub pubkey: Vec<u8>,
+    pub signature: Vec<u8>,
 }
 
 impl_from_core_type!(Psbt, bitcoin::Psbt);
@@ -0,0 +1,163 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::error::PaymentUriError;
+use crate::{Address, Amount};
+
+const SCHEME: &str = "bitcoin:";
+
+/// A parsed BIP21 `bitcoin:` payment URI: an address plus the optional amount, label and
+/// message a wallet should pre-fill when a user scans a merchant QR code.
+#[derive(Debug, Clone, uniffi::Object)]
+pub struct PaymentUri {
+    address: Arc<Address>,
+    amount: Option<Arc<Amount>>,
+    label: Option<String>,
+    message: Option<String>,
+    unknown_required_params: Vec<String>,
+}
+
+#[uniffi::export]
+impl PaymentUri {
+    #[uniffi::constructor]
+    pub fn parse(uri: String) -> Result<Self, PaymentUriError> {
+        let rest = match uri.get(..SCHEME.len()) {
+            Some(prefix) if prefix.eq_ignore_ascii_case(SCHEME) => &uri[SCHEME.len()..],
+            _ => return Err(PaymentUriError::InvalidScheme),
+        };
+
+        let (address_str, query) = match rest.split_once('?') {
+            Some((address_str, query)) => (address_str, Some(query)),
+            None => (rest, None),
+        };
+
+        if address_str.is_empty() {
+            return Err(PaymentUriError::MissingAddress);
+        }
+        let address = bitcoin::Address::from_str(address_str)
+            .map_err(|e| PaymentUriError::InvalidAddress {
+                error_message: e.to_string(),
+            })?
+            .assume_checked();
+
+        let mut amount = None;
+        let mut label = None;
+        let mut message = None;
+        let mut unknown_required_params = Vec::new();
+
+        for pair in query.unwrap_or_default().split('&').filter(|s| !s.is_empty()) {
+            let (key, value) = match pair.split_once('=') {
+                Some((key, value)) => (key, value),
+                None => (pair, ""),
+            };
+            let key = percent_decode(key)?;
+            let value = percent_decode(value)?;
+
+            match key.as_str() {
+                "amount" => {
+                    let parsed =
+                        bitcoin::Amount::from_str_in(&value, bitcoin::Denomination::Bitcoin)
+                            .map_err(|e| PaymentUriError::InvalidAmount {
+                                error_message: e.to_string(),
+                            })?;
+                    amount = Some(Arc::new(Amount(parsed)));
+                }
+                "label" => label = Some(value),
+                "message" => message = Some(value),
+                _ if key.starts_with("req-") => unknown_required_params.push(key),
+                _ => {}
+            }
+        }
+
+        Ok(PaymentUri {
+            address: Arc::new(address.into()),
+            amount,
+            label,
+            message,
+            unknown_required_params,
+        })
+    }
+
+    pub fn address(&self) -> Arc<Address> {
+        self.address.clone()
+    }
+
+    pub fn amount(&self) -> Option<Arc<Amount>> {
+        self.amount.clone()
+    }
+
+    pub fn label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    pub fn message(&self) -> Option<String> {
+        self.message.clone()
+    }
+
+    /// `req-`-prefixed query parameters this parser did not recognize. BIP21 requires rejecting
+    /// the payment if the wallet doesn't understand a required parameter; callers decide that.
+    pub fn unknown_required_params(&self) -> Vec<String> {
+        self.unknown_required_params.clone()
+    }
+
+    pub fn to_uri(&self) -> String {
+        let mut uri = format!("{}{}", SCHEME, self.address.0);
+        let mut params = Vec::new();
+
+        if let Some(amount) = &self.amount {
+            params.push(format!(
+                "amount={}",
+                amount.0.to_string_in(bitcoin::Denomination::Bitcoin)
+            ));
+        }
+        if let Some(label) = &self.label {
+            params.push(format!("label={}", percent_encode(label)));
+        }
+        if let Some(message) = &self.message {
+            params.push(format!("message={}", percent_encode(message)));
+        }
+
+        if !params.is_empty() {
+            uri.push('?');
+            uri.push_str(&params.join("&"));
+        }
+        uri
+    }
+}
+
+fn percent_decode(value: &str) -> Result<String, PaymentUriError> {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = value
+                    .get(i + 1..i + 3)
+                    .ok_or(PaymentUriError::InvalidParameter)?;
+                let byte = u8::from_str_radix(hex, 16)
+                    .map_err(|_| PaymentUriError::InvalidParameter)?;
+                out.push(byte);
+                i += 3;
+            }
+            byte => {
+                out.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(out).map_err(|_| PaymentUriError::InvalidParameter)
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
@@ -0,0 +1,155 @@
+use std::sync::{Arc, Mutex};
+
+use bitcoin::hashes::Hash;
+use bitcoin::sighash::Prevouts;
+use bitcoin::taproot::TapLeafHash;
+
+use crate::error::SighashError;
+use crate::{Amount, Script, Transaction, TxOut};
+
+/// The standard sighash flags, shared between legacy/SegWit v0 (ECDSA) and Taproot (BIP341)
+/// signature hashing. `Default` is only meaningful for Taproot, where it is distinct from `All`.
+#[derive(Clone, Copy, Debug, uniffi::Enum)]
+pub enum SighashType {
+    Default,
+    All,
+    None,
+    Single,
+    AllPlusAnyoneCanPay,
+    NonePlusAnyoneCanPay,
+    SinglePlusAnyoneCanPay,
+}
+
+impl From<SighashType> for bitcoin::EcdsaSighashType {
+    fn from(sighash_type: SighashType) -> Self {
+        match sighash_type {
+            SighashType::Default | SighashType::All => bitcoin::EcdsaSighashType::All,
+            SighashType::None => bitcoin::EcdsaSighashType::None,
+            SighashType::Single => bitcoin::EcdsaSighashType::Single,
+            SighashType::AllPlusAnyoneCanPay => bitcoin::EcdsaSighashType::AllPlusAnyoneCanPay,
+            SighashType::NonePlusAnyoneCanPay => bitcoin::EcdsaSighashType::NonePlusAnyoneCanPay,
+            SighashType::SinglePlusAnyoneCanPay => {
+                bitcoin::EcdsaSighashType::SinglePlusAnyoneCanPay
+            }
+        }
+    }
+}
+
+impl From<SighashType> for bitcoin::TapSighashType {
+    fn from(sighash_type: SighashType) -> Self {
+        match sighash_type {
+            SighashType::Default => bitcoin::TapSighashType::Default,
+            SighashType::All => bitcoin::TapSighashType::All,
+            SighashType::None => bitcoin::TapSighashType::None,
+            SighashType::Single => bitcoin::TapSighashType::Single,
+            SighashType::AllPlusAnyoneCanPay => bitcoin::TapSighashType::AllPlusAnyoneCanPay,
+            SighashType::NonePlusAnyoneCanPay => bitcoin::TapSighashType::NonePlusAnyoneCanPay,
+            SighashType::SinglePlusAnyoneCanPay => bitcoin::TapSighashType::SinglePlusAnyoneCanPay,
+        }
+    }
+}
+
+/// Computes the message digests needed to sign a transaction's inputs, across legacy, SegWit v0
+/// and Taproot sighash algorithms, mirroring `bitcoin::sighash::SighashCache`.
+#[derive(uniffi::Object)]
+pub struct SighashCache(Mutex<bitcoin::sighash::SighashCache<bitcoin::Transaction>>);
+
+#[uniffi::export]
+impl SighashCache {
+    #[uniffi::constructor]
+    pub fn new(tx: Arc<Transaction>) -> Self {
+        SighashCache(Mutex::new(bitcoin::sighash::SighashCache::new(
+            tx.0.clone(),
+        )))
+    }
+
+    pub fn legacy_signature_hash(
+        &self,
+        input_index: u32,
+        script_pubkey: Arc<Script>,
+        sighash_type: SighashType,
+    ) -> Result<Vec<u8>, SighashError> {
+        let mut cache = self.0.lock().unwrap();
+        let sighash = cache.legacy_signature_hash(
+            input_index as usize,
+            &script_pubkey.0,
+            bitcoin::EcdsaSighashType::from(sighash_type).to_u32(),
+        )?;
+        Ok(sighash.to_byte_array().to_vec())
+    }
+
+    pub fn p2wsh_signature_hash(
+        &self,
+        input_index: u32,
+        witness_script: Arc<Script>,
+        value: Arc<Amount>,
+        sighash_type: SighashType,
+    ) -> Result<Vec<u8>, SighashError> {
+        let mut cache = self.0.lock().unwrap();
+        let sighash = cache.p2wsh_signature_hash(
+            input_index as usize,
+            &witness_script.0,
+            value.0,
+            bitcoin::EcdsaSighashType::from(sighash_type),
+        )?;
+        Ok(sighash.to_byte_array().to_vec())
+    }
+
+    pub fn p2wpkh_signature_hash(
+        &self,
+        input_index: u32,
+        script_pubkey: Arc<Script>,
+        value: Arc<Amount>,
+        sighash_type: SighashType,
+    ) -> Result<Vec<u8>, SighashError> {
+        let mut cache = self.0.lock().unwrap();
+        let sighash = cache.p2wpkh_signature_hash(
+            input_index as usize,
+            &script_pubkey.0,
+            value.0,
+            bitcoin::EcdsaSighashType::from(sighash_type),
+        )?;
+        Ok(sighash.to_byte_array().to_vec())
+    }
+
+    pub fn taproot_key_spend_signature_hash(
+        &self,
+        input_index: u32,
+        prevouts: Vec<TxOut>,
+        sighash_type: SighashType,
+    ) -> Result<Vec<u8>, SighashError> {
+        let prevouts: Vec<bitcoin::TxOut> = prevouts.into_iter().map(Into::into).collect();
+        let prevouts = Prevouts::All(&prevouts);
+        let mut cache = self.0.lock().unwrap();
+        let sighash = cache.taproot_key_spend_signature_hash(
+            input_index as usize,
+            &prevouts,
+            bitcoin::TapSighashType::from(sighash_type),
+        )?;
+        Ok(sighash.to_byte_array().to_vec())
+    }
+
+    pub fn taproot_script_spend_signature_hash(
+        &self,
+        input_index: u32,
+        prevouts: Vec<TxOut>,
+        leaf_hash: Vec<u8>,
+        sighash_type: SighashType,
+    ) -> Result<Vec<u8>, SighashError> {
+        let leaf_hash_bytes: [u8; 32] = leaf_hash
+            .try_into()
+            .map_err(|_| SighashError::InvalidLeafHash)?;
+        let leaf_hash = TapLeafHash::from_byte_array(leaf_hash_bytes);
+
+        let prevouts: Vec<bitcoin::TxOut> = prevouts.into_iter().map(Into::into).collect();
+        let prevouts = Prevouts::All(&prevouts);
+        let mut cache = self.0.lock().unwrap();
+        let sighash = cache.taproot_script_spend_signature_hash(
+            input_index as usize,
+            &prevouts,
+            leaf_hash,
+            bitcoin::TapSighashType::from(sighash_type),
+        )?;
+        Ok(sighash.to_byte_array().to_vec())
+    }
+}